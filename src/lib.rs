@@ -17,6 +17,17 @@
 pub mod util;
 use util::*;
 
+#[cfg(feature = "num-rational")]
+/// Fast fraction reduction for [`num_rational::Ratio`], built on this
+/// crate's binary GCD.
+pub mod ratio;
+
+#[cfg(test)]
+mod test_util;
+
+#[cfg(all(test, feature = "rand"))]
+mod proptests;
+
 /// GCD of 2 arguments.
 pub fn gcd2<T, U>(a: T, b: T) -> U
 where
@@ -125,14 +136,20 @@ where
             return s;
         }
     }
+    let mut len = vec.len();
     loop {
-        vec.sort_by(|a, b| b.cmp(a));
+        vec[..len].sort_by(|a, b| b.cmp(a));
+        // a zero can only end up last among nonnegative magnitudes sorted
+        // descending; everything from here on contributes nothing further
+        while len > 1 && vec[len - 1] == T::zero() {
+            len -= 1;
+        }
+        if len == 1 {
+            return uabs(vec[0]) * s;
+        }
         let mut prev = 0;
-        for i in 1..vec.len() {
+        for i in 1..len {
             let x = vec[i];
-            if x == T::zero() {
-                return uabs(vec[prev]) * s;
-            }
             let x = vec[prev].wrapping_sub(&x);
             let x = x.wrapping_shr(x.trailing_zeros());
             vec[prev] = x;
@@ -141,6 +158,98 @@ where
     }
 }
 
+/// GCD of a fixed-size array of arguments.
+///
+/// Unlike [`gcdn`], this copies `xs` onto the stack instead of mutating the
+/// caller's slice, so it also works in `no_std` contexts without a buffer.
+/// For `N <= 4` this defers to the hand-unrolled [`gcd2`]/[`gcd3`]/[`gcd4`].
+pub fn gcd_arr<const N: usize, T, U>(mut xs: [T; N]) -> U
+where
+    T: PrimInt + WrappingSub + WrappingShr + UAbs<U>,
+    U: PrimInt + WrappingSub + WrappingShr,
+{
+    if N == 0 {
+        return U::one();
+    }
+    if N == 1 {
+        return uabs(xs[0]);
+    }
+    if N == 2 {
+        return gcd2(xs[0], xs[1]);
+    }
+    if N == 3 {
+        return gcd3(xs[0], xs[1], xs[2]);
+    }
+    if N == 4 {
+        return gcd4(xs[0], xs[1], xs[2], xs[3]);
+    }
+    let mut or = xs[0];
+    for x in xs.iter() {
+        if *x == T::one() {
+            return U::one();
+        }
+        or = or | *x;
+    }
+    let s = expot(uabs(or));
+    for x in xs.iter_mut() {
+        *x = iabs(unpot(uabs(*x)));
+        if *x == T::one() {
+            return s;
+        }
+    }
+    let mut len = N;
+    loop {
+        xs[..len].sort_by(|a, b| b.cmp(a));
+        // a zero can only end up last among nonnegative magnitudes sorted
+        // descending; everything from here on contributes nothing further
+        while len > 1 && xs[len - 1] == T::zero() {
+            len -= 1;
+        }
+        if len == 1 {
+            return uabs(xs[0]) * s;
+        }
+        let mut prev = 0;
+        for i in 1..len {
+            let x = xs[i];
+            let x = xs[prev].wrapping_sub(&x);
+            let x = x.wrapping_shr(x.trailing_zeros());
+            xs[prev] = x;
+            prev = i;
+        }
+    }
+}
+
+/// GCD of the values yielded by an iterator, folding with [`gcd2`] instead
+/// of requiring a mutable slice like [`gcdn`].
+///
+/// Short-circuits to `U::one()` as soon as a unit is seen, same as
+/// [`gcdn`].
+pub fn gcd_iter<I, T, U>(iter: I) -> U
+where
+    I: IntoIterator<Item = T>,
+    T: UAbs<U> + PrimInt,
+    U: PrimInt + WrappingShr + WrappingSub,
+{
+    let mut iter = iter.into_iter();
+    let mut acc: U = match iter.next() {
+        Some(x) => uabs(x),
+        None => return U::one(),
+    };
+    if acc == U::one() {
+        return U::one();
+    }
+    for x in iter {
+        if x == T::one() {
+            return U::one();
+        }
+        acc = gcd2(iabs(acc), x);
+        if acc == U::one() {
+            return U::one();
+        }
+    }
+    acc
+}
+
 /// LCM of 2 arguments.
 pub fn lcm2<T, U>(a: T, b: T) -> U
 where
@@ -182,6 +291,126 @@ where
     lcm2(iabs(lcm2(iabs(lcm2(a, b)), c)), d)
 }
 
+/// LCM of a fixed-size array of arguments.
+///
+/// For `N <= 4` this defers to the hand-unrolled [`lcm2`]/[`lcm3`]/[`lcm4`];
+/// larger arrays fold [`lcm2`] across the array in order.
+pub fn lcm_arr<const N: usize, T, U>(xs: [T; N]) -> U
+where
+    T: PrimInt + UAbs<U>,
+    U: PrimInt + WrappingShr + WrappingSub,
+{
+    match N {
+        0 => U::one(),
+        1 => uabs(xs[0]),
+        2 => lcm2(xs[0], xs[1]),
+        3 => lcm3(xs[0], xs[1], xs[2]),
+        4 => lcm4(xs[0], xs[1], xs[2], xs[3]),
+        _ => {
+            let mut acc: U = lcm2(xs[0], xs[1]);
+            for x in xs.into_iter().skip(2) {
+                acc = lcm2(iabs(acc), x);
+            }
+            acc
+        }
+    }
+}
+
+/// LCM of the values yielded by an iterator, folding with [`lcm2`] instead
+/// of requiring a mutable slice.
+pub fn lcm_iter<I, T, U>(iter: I) -> U
+where
+    I: IntoIterator<Item = T>,
+    T: UAbs<U>,
+    U: PrimInt + WrappingShr + WrappingSub,
+{
+    let mut iter = iter.into_iter();
+    let mut acc: U = match iter.next() {
+        Some(x) => uabs(x),
+        None => return U::one(),
+    };
+    for x in iter {
+        acc = lcm2(iabs(acc), x);
+    }
+    acc
+}
+
+/// LCM of a slice of values.
+///
+/// Accumulates `lcm = lcm / gcd2(lcm, x) * x` for each `x` in turn; pass
+/// values in ascending order to keep the intermediate product as small as
+/// possible and minimize the chance of overflow.
+pub fn lcmn<T, U>(xs: &[T]) -> U
+where
+    T: UAbs<U> + PrimInt,
+    U: UAbs<U> + PrimInt + WrappingShr + WrappingSub,
+{
+    let mut iter = xs.iter().copied();
+    let mut lcm: U = match iter.next() {
+        Some(x) => uabs(x),
+        None => return U::one(),
+    };
+    for x in iter {
+        let x: U = uabs(x);
+        let g: U = gcd2(lcm, x);
+        if g > U::zero() {
+            lcm = (lcm / g) * x;
+        }
+    }
+    lcm
+}
+
+/// Extended GCD of 2 arguments.
+///
+/// Returns the GCD together with Bézout coefficients `x, y` satisfying
+/// `a*x + b*y = gcd(a, b)`. `S` is a separate signed type for the
+/// coefficients, since they need signed storage even when `T` and `U` are
+/// unsigned.
+pub fn egcd2<T, U, S>(a: T, b: T) -> (U, S, S)
+where
+    T: UAbs<U> + PrimInt,
+    U: PrimInt,
+    S: PrimInt + Signed,
+{
+    let mut old_r = a;
+    let mut r = b;
+    let mut old_s = S::one();
+    let mut s = S::zero();
+    let mut old_t = S::zero();
+    let mut t = S::one();
+    while r != T::zero() {
+        let q = old_r / r;
+        let q_s = S::from(q).unwrap();
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q_s * s);
+        (old_t, t) = (t, old_t - q_s * t);
+    }
+    // with signed inputs the recurrence can end on -gcd; flip the
+    // coefficients so they match the returned (nonnegative) gcd
+    if old_r < T::zero() {
+        return (uabs(old_r), -old_s, -old_t);
+    }
+    (uabs(old_r), old_s, old_t)
+}
+
+/// Modular inverse of `a` modulo `m`, or `None` if `gcd(a, m) != 1`.
+///
+/// Built on [`egcd2`]; `S` is the signed scratch type used to carry its
+/// Bézout coefficients.
+pub fn mod_inverse<T, S>(a: T, m: T) -> Option<T>
+where
+    T: UAbs<T> + PrimInt,
+    S: PrimInt + Signed,
+{
+    let (g, x, _): (T, S, S) = egcd2(a, m);
+    if g != T::one() {
+        return None;
+    }
+    let m_s = S::from(m).unwrap();
+    let x = ((x % m_s) + m_s) % m_s;
+    Some(T::from(x).unwrap())
+}
+
 #[cfg(test)]
 mod tests {
     use crate::*;
@@ -244,6 +473,36 @@ mod tests {
         assert_eq!(gcdn(&mut [4, 16, 0, 4, 8]), 4u32);
         assert_eq!(gcdn(&mut [4, 0, 16, 4, 8]), 4u32);
         assert_eq!(gcdn(&mut [0, 4, 16, 4, 8]), 4u32);
+        // regression: a zero alongside coprime values, none of which is
+        // individually a power of two, used to return a wrong nonzero gcd
+        assert_eq!(gcdn(&mut [0, 3, 5]), 1u32);
+    }
+
+    #[test]
+    fn test_gcd_arr() {
+        assert_eq!(gcd_arr([0]), 0u32);
+        assert_eq!(gcd_arr([4, 3]), 1u32);
+        assert_eq!(gcd_arr([5, 4, 3]), 1u32);
+        assert_eq!(gcd_arr([4, 5, 4, 3]), 1u32);
+        assert_eq!(gcd_arr([21, 7, 14, 28]), 7u32);
+        assert_eq!(gcd_arr([15, 120, 30, 25]), 5u32);
+        assert_eq!(gcd_arr([4, 16, 4, 8, 0]), 4u32);
+        assert_eq!(gcd_arr([4, 16, 0, 4, 8]), 4u32);
+        assert_eq!(gcd_arr([0, 4, 16, 4, 8, 32]), 4u32);
+        // regression: a zero alongside coprime values, none of which is
+        // individually a power of two, used to return a wrong nonzero gcd
+        assert_eq!(gcd_arr([0, 3, 5, 7, 11]), 1u32);
+    }
+
+    #[test]
+    fn test_gcd_iter() {
+        assert_eq!(gcd_iter([0i32]), 0u32);
+        assert_eq!(gcd_iter([4, 3]), 1u32);
+        assert_eq!(gcd_iter([21, 7, 14, 28]), 7u32);
+        assert_eq!(gcd_iter([15, 120, 30, 25]), 5u32);
+        assert_eq!(gcd_iter([4, 16, 0, 4, 8]), 4u32);
+        assert_eq!(gcd_iter([4, 16, 1, 4, 8]), 1u32);
+        assert_eq!(gcd_iter(core::iter::empty::<i32>()), 1u32);
     }
 
     #[test]
@@ -276,4 +535,57 @@ mod tests {
         assert_eq!(lcm4(4, 4, 4, 2), 4u32);
         assert_eq!(lcm4(4, 16, 4, 8), 16u32);
     }
+
+    #[test]
+    fn test_lcm_arr() {
+        assert_eq!(lcm_arr([5, 3]), 15u32);
+        assert_eq!(lcm_arr([5, 4, 3]), 60u32);
+        assert_eq!(lcm_arr([4, 5, 4, 3]), 60u32);
+        assert_eq!(lcm_arr([21, 7, 14, 28]), 84u32);
+        assert_eq!(lcm_arr([15, 120, 30, 25]), 600u32);
+        assert_eq!(lcm_arr([4, 5, 3, 4, 6]), 60u32);
+    }
+
+    #[test]
+    fn test_lcm_iter() {
+        assert_eq!(lcm_iter([5i32, 3]), 15u32);
+        assert_eq!(lcm_iter([5, 4, 3]), 60u32);
+        assert_eq!(lcm_iter([21, 7, 14, 28]), 84u32);
+        assert_eq!(lcm_iter([15, 120, 30, 25]), 600u32);
+        assert_eq!(lcm_iter(core::iter::empty::<i32>()), 1u32);
+    }
+
+    #[test]
+    fn test_lcmn() {
+        assert_eq!(lcmn::<i32, u32>(&[]), 1u32);
+        assert_eq!(lcmn(&[5, 3]), 15u32);
+        assert_eq!(lcmn(&[3, 4, 5]), 60u32);
+        assert_eq!(lcmn(&[7, 14, 21, 28]), 84u32);
+        assert_eq!(lcmn(&[5, 15, 25, 120]), 600u32);
+    }
+
+    #[test]
+    fn test_egcd2() {
+        let (g1, x1, y1): (u32, i32, i32) = egcd2(35, 15);
+        assert_eq!(g1, 5u32);
+        assert_eq!(35 * x1 + 15 * y1, 5);
+
+        let (g2, x2, y2): (u32, i32, i32) = egcd2(240, 46);
+        assert_eq!(g2, 2u32);
+        assert_eq!(240 * x2 + 46 * y2, 2);
+
+        let (g3, x3, y3): (u32, i32, i32) = egcd2(17, 5);
+        assert_eq!(g3, 1u32);
+        assert_eq!(17 * x3 + 5 * y3, 1);
+
+        let (g4, _, _): (u32, i32, i32) = egcd2(7, 0);
+        assert_eq!(g4, 7u32);
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(mod_inverse::<u32, i64>(3, 11), Some(4));
+        assert_eq!(mod_inverse::<u32, i64>(10, 17), Some(12));
+        assert_eq!(mod_inverse::<u32, i64>(6, 9), None);
+    }
 }
@@ -0,0 +1,51 @@
+//! Randomized differential tests that check [`crate::gcdn`] against
+//! [`crate::test_util::reference_gcd`] and that it is invariant under
+//! permutation of its input. Gated behind the `rand` feature since it
+//! pulls in `rand`'s thread-local RNG and shuffling, which this otherwise
+//! `no_std` crate has no other use for.
+#![cfg(all(test, feature = "rand"))]
+
+extern crate std;
+
+use crate::test_util::reference_gcd;
+use crate::*;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::vec::Vec;
+
+const ITERATIONS: usize = 256;
+
+fn check(mut xs: Vec<i32>) {
+    let mut rng = rand::thread_rng();
+    let expected = reference_gcd(&xs);
+    xs.shuffle(&mut rng);
+    let input = xs.clone();
+    let actual: i32 = iabs(gcdn::<i32, u32>(&mut xs));
+    assert_eq!(actual, expected, "gcdn disagreed with reference_gcd on {input:?}");
+}
+
+#[test]
+fn gcdn_matches_reference_under_random_shuffles() {
+    let mut rng = rand::thread_rng();
+    for _ in 0..ITERATIONS {
+        let len = rng.gen_range(2..8);
+        let xs: Vec<i32> = (0..len).map(|_| rng.gen_range(-1000..=1000)).collect();
+        check(xs);
+    }
+}
+
+#[test]
+fn gcdn_handles_negative_zero_and_unit_edge_cases() {
+    let cases: [&[i32]; 7] = [
+        &[0, 0, 0],
+        &[0, 5],
+        &[-5, 0],
+        &[0, 3, 5],
+        &[1, 100, -7],
+        &[-1, -1, -1],
+        &[-12, 18, -24],
+    ];
+    for xs in cases {
+        check(xs.to_vec());
+    }
+}
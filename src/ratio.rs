@@ -0,0 +1,130 @@
+//! Optional integration with [`num_rational`], reducing fractions with this
+//! crate's binary GCD instead of the generic Euclidean one `Ratio` uses
+//! internally.
+
+use crate::util::*;
+use num_rational::Ratio;
+
+/// Reduces `num / den` with [`gcd2`](crate::gcd2) and returns the result as
+/// a [`Ratio`] of the unsigned magnitude type `U`.
+///
+/// The sign is fixed up in the signed `T` domain exactly like
+/// [`reduce_in_place`] does, then the already-reduced, correctly-signed
+/// terms are turned into the magnitude pair `U` expects (`U` is the
+/// unsigned type [`UAbs`] maps `T` to, so it cannot carry a sign itself).
+pub fn reduce_ratio<T, U>(num: T, den: T) -> Ratio<U>
+where
+    T: UAbs<U> + PrimInt + Signed,
+    U: PrimInt + WrappingShr + WrappingSub,
+{
+    let mut r = Ratio::new_raw(num, den);
+    reduce_in_place::<T, U>(&mut r);
+    Ratio::new_raw(uabs(*r.numer()), uabs(*r.denom()))
+}
+
+/// Divides `r`'s numerator and denominator in place by
+/// [`gcd2`](crate::gcd2), fixing up the sign so the denominator stays
+/// positive.
+pub fn reduce_in_place<T, U>(r: &mut Ratio<T>)
+where
+    T: UAbs<U> + PrimInt + Signed,
+    U: PrimInt + WrappingShr + WrappingSub,
+{
+    let g: U = crate::gcd2(*r.numer(), *r.denom());
+    let g: T = iabs(g);
+    let g = if g == T::zero() { T::one() } else { g };
+    let n = *r.numer() / g;
+    let d = *r.denom() / g;
+    *r = if d < T::zero() {
+        Ratio::new_raw(-n, -d)
+    } else {
+        Ratio::new_raw(n, d)
+    };
+}
+
+/// Adds two ratios, reducing the cross terms (the two denominators) with
+/// [`gcdn`](crate::gcdn) before multiplying them out, to keep the
+/// intermediate common denominator from overflowing.
+pub fn ratio_add<T, U>(a: Ratio<T>, b: Ratio<T>) -> Ratio<T>
+where
+    T: UAbs<U> + PrimInt + Signed + WrappingSub + WrappingShr,
+    U: PrimInt + WrappingShr + WrappingSub,
+{
+    let (an, ad) = (*a.numer(), *a.denom());
+    let (bn, bd) = (*b.numer(), *b.denom());
+    let g: U = crate::gcdn(&mut [ad, bd]);
+    let g: T = iabs(g);
+    let g = if g == T::zero() { T::one() } else { g };
+    let den = (ad / g) * bd;
+    let num = an * (bd / g) + bn * (ad / g);
+    let mut r = Ratio::new_raw(num, den);
+    reduce_in_place(&mut r);
+    r
+}
+
+/// Multiplies two ratios, reducing each numerator against the other
+/// ratio's denominator with [`gcdn`](crate::gcdn) before multiplying, to
+/// keep the intermediate numerator and denominator from overflowing.
+pub fn ratio_mul<T, U>(a: Ratio<T>, b: Ratio<T>) -> Ratio<T>
+where
+    T: UAbs<U> + PrimInt + Signed + WrappingSub + WrappingShr,
+    U: PrimInt + WrappingShr + WrappingSub,
+{
+    let (an, ad) = (*a.numer(), *a.denom());
+    let (bn, bd) = (*b.numer(), *b.denom());
+    let g1: U = crate::gcdn(&mut [an, bd]);
+    let g1: T = iabs(g1);
+    let g1 = if g1 == T::zero() { T::one() } else { g1 };
+    let g2: U = crate::gcdn(&mut [bn, ad]);
+    let g2: T = iabs(g2);
+    let g2 = if g2 == T::zero() { T::one() } else { g2 };
+    let num = (an / g1) * (bn / g2);
+    let den = (ad / g2) * (bd / g1);
+    let mut r = Ratio::new_raw(num, den);
+    reduce_in_place(&mut r);
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reduce_ratio() {
+        let pp: Ratio<u32> = reduce_ratio(10i32, 4i32);
+        assert_eq!((*pp.numer(), *pp.denom()), (5, 2));
+
+        let np: Ratio<u32> = reduce_ratio(-10i32, 4i32);
+        assert_eq!((*np.numer(), *np.denom()), (5, 2));
+
+        let pn: Ratio<u32> = reduce_ratio(10i32, -4i32);
+        assert_eq!((*pn.numer(), *pn.denom()), (5, 2));
+    }
+
+    #[test]
+    fn test_reduce_in_place() {
+        let mut pos = Ratio::new_raw(10i32, 4i32);
+        reduce_in_place::<i32, u32>(&mut pos);
+        assert_eq!((*pos.numer(), *pos.denom()), (5, 2));
+
+        let mut neg = Ratio::new_raw(10i32, -4i32);
+        reduce_in_place::<i32, u32>(&mut neg);
+        assert_eq!((*neg.numer(), *neg.denom()), (-5, 2));
+    }
+
+    #[test]
+    fn test_ratio_add() {
+        let a = Ratio::new_raw(1i32, 2i32);
+        let b = Ratio::new_raw(1i32, 3i32);
+        let r: Ratio<i32> = ratio_add::<i32, u32>(a, b);
+        assert_eq!((*r.numer(), *r.denom()), (5, 6));
+    }
+
+    #[test]
+    fn test_ratio_mul() {
+        let a = Ratio::new_raw(2i32, 3i32);
+        let b = Ratio::new_raw(3i32, 4i32);
+        let r: Ratio<i32> = ratio_mul::<i32, u32>(a, b);
+        assert_eq!((*r.numer(), *r.denom()), (1, 2));
+    }
+}
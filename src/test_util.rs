@@ -0,0 +1,28 @@
+//! Reference implementation used to differentially test the optimized
+//! GCD functions against.
+#![cfg(test)]
+
+use crate::util::*;
+
+/// GCD of a slice via plain repeated Euclidean remainder.
+///
+/// Used as an oracle in differential tests of [`crate::gcdn`] and its
+/// relatives; deliberately avoids any of the subtraction/shift tricks they
+/// use, so a bug shared between this and them would have to be identical.
+pub fn reference_gcd<T>(xs: &[T]) -> T
+where
+    T: PrimInt,
+{
+    let mut acc = T::zero();
+    for &x in xs {
+        let mut a = acc;
+        let mut b = x;
+        while b != T::zero() {
+            let r = a % b;
+            a = b;
+            b = r;
+        }
+        acc = if a < T::zero() { T::zero() - a } else { a };
+    }
+    acc
+}